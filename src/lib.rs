@@ -1,5 +1,14 @@
-use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Visitor};
-use std::{borrow::Cow, panic::Location as StdLocation};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Visitor},
+};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashSet,
+    panic::Location as StdLocation,
+    sync::{Arc, Mutex, OnceLock},
+};
 
 use anyhow::anyhow;
 
@@ -15,54 +24,258 @@ pub struct LocationError {
     pub backtrace: Vec<Location>,
 }
 
+/// The serialized shape of [`LocationError::source`].
+///
+/// A registered [`SerLocError`] at the root of the chain round-trips as
+/// `Tagged`, keeping its concrete
+/// type (and thus `downcast_ref`) alive across transport. Everything else keeps
+/// its full `anyhow` context `Chain` (outermost-to-innermost) rather than being
+/// flattened into one `{value:#?}` blob. `Legacy` accepts the bare error string
+/// emitted by older peers so old payloads still deserialize.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SerializedSource {
+    Tagged {
+        tag: String,
+        payload: String,
+        chain: Vec<String>,
+    },
+    Chain {
+        display: String,
+        chain: Vec<String>,
+    },
+    Legacy(String),
+}
+
 fn serialize_source<S: Serializer>(
     value: &anyhow::Error,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
-    serializer.serialize_str(&format!("{value:#?}"))
+    let chain = value.chain().map(|cause| cause.to_string()).collect();
+    let serialized = match registry_serialize(value) {
+        // Carry the full context chain alongside the tag so the tagged path
+        // keeps the concrete type *and* the `.context(...)` frames — dropping
+        // the chain here would undo the round-trip guarantee made elsewhere.
+        Some((tag, payload)) => SerializedSource::Tagged { tag, payload, chain },
+        None => SerializedSource::Chain {
+            display: format!("{value}"),
+            chain,
+        },
+    };
+    serialized.serialize(serializer)
 }
 
 fn deserialize_source<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<anyhow::Error, D::Error> {
-    struct SourceVisitor;
-    impl Visitor<'_> for SourceVisitor {
-        type Value = anyhow::Error;
+    Ok(match SerializedSource::deserialize(deserializer)? {
+        SerializedSource::Tagged {
+            tag,
+            payload,
+            chain,
+        } => match registry_lookup(&tag) {
+            // Seed the root from the reconstructed concrete error (the innermost
+            // cause) and re-apply the outer context frames, matching the
+            // original `.chain()` and `Display`/`Debug`.
+            Some(entry) => reapply_context((entry.reconstruct)(&payload), chain),
+            // Unknown tag (the type was not registered on this side): keep the
+            // chain as opaque strings rather than failing the parse.
+            None => rebuild_source(String::new(), chain),
+        },
+        SerializedSource::Chain { display, chain } => rebuild_source(display, chain),
+        SerializedSource::Legacy(display) => anyhow!(DisplayString(display)),
+    })
+}
 
-        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-            formatter.write_str("error string")
-        }
+/// Re-apply the outer `.context(...)` frames of a serialized `chain` on top of
+/// an already-reconstructed root error. The chain is stored
+/// outermost-to-innermost and its innermost entry is the root itself, so we
+/// drop that entry and replay the rest in reverse.
+fn reapply_context(root: anyhow::Error, chain: Vec<String>) -> anyhow::Error {
+    let outer = chain.len().saturating_sub(1);
+    chain
+        .into_iter()
+        .take(outer)
+        .rev()
+        .fold(root, |error, frame| error.context(frame))
+}
+
+/// Rebuild an [`anyhow::Error`] from its serialized chain. The chain is stored
+/// outermost-to-innermost, so we seed the root from the innermost cause and
+/// re-apply the remaining frames in reverse with `.context(...)`, reproducing
+/// the original `.chain()` sequence and `Display`/`Debug` output.
+fn rebuild_source(display: String, chain: Vec<String>) -> anyhow::Error {
+    let mut frames = chain.into_iter().rev();
+    let mut error = match frames.next() {
+        Some(root) => anyhow!(DisplayString(root)),
+        None => return anyhow!(DisplayString(display)),
+    };
+    for frame in frames {
+        error = error.context(frame);
+    }
+    error
+}
+
+/// A concrete error type that can be carried through a [`LocationError`] serde
+/// round-trip without degrading to a string. Register the type with
+/// [`register_ser_loc_error!`] and a receiver that also knows the type can
+/// recover it with `source.downcast_ref::<T>()`.
+pub trait SerLocError:
+    std::error::Error + Serialize + serde::de::DeserializeOwned + Send + Sync + 'static
+{
+    /// A stable tag identifying this type on the wire. Keep it unique across
+    /// every registered type and unchanged across versions.
+    const TAG: &'static str;
+}
 
-        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-        where
-            E: serde::de::Error,
-        {
-            let err_str = DisplayString(v.into());
-            Ok(anyhow!(err_str))
+/// One entry in the tag → reconstructor registry, populated at link time by
+/// [`register_ser_loc_error!`] via `inventory`.
+pub struct SerLocErrorEntry {
+    /// The type's stable wire tag ([`SerLocError::TAG`]).
+    pub tag: &'static str,
+    /// Attempts to downcast the root cause of `source` to this type and
+    /// serialize its payload.
+    pub serialize: fn(&anyhow::Error) -> Option<String>,
+    /// Rebuilds the concrete error (as an [`anyhow::Error`]) from a payload.
+    pub reconstruct: fn(&str) -> anyhow::Error,
+}
+
+inventory::collect!(SerLocErrorEntry);
+
+fn registry_serialize(value: &anyhow::Error) -> Option<(String, String)> {
+    inventory::iter::<SerLocErrorEntry>
+        .into_iter()
+        .find_map(|entry| (entry.serialize)(value).map(|payload| (entry.tag.to_owned(), payload)))
+}
+
+fn registry_lookup(tag: &str) -> Option<&'static SerLocErrorEntry> {
+    inventory::iter::<SerLocErrorEntry>
+        .into_iter()
+        .find(|entry| entry.tag == tag)
+}
+
+/// Register a [`SerLocError`] implementor so its concrete type survives a
+/// [`LocationError`] serde round-trip. Implements [`SerLocError`] with the given
+/// tag and submits a reconstructor to the global registry.
+///
+/// ```ignore
+/// register_ser_loc_error!(MyError, "my_error");
+/// ```
+#[macro_export]
+macro_rules! register_ser_loc_error {
+    ($ty:ty, $tag:literal) => {
+        impl $crate::SerLocError for $ty {
+            const TAG: &'static str = $tag;
         }
 
-        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-        where
-            E: serde::de::Error,
-        {
-            let err_str = DisplayString(v);
-            Ok(anyhow!(err_str))
+        $crate::inventory::submit! {
+            $crate::SerLocErrorEntry {
+                tag: $tag,
+                serialize: |source: &::anyhow::Error| {
+                    source
+                        .root_cause()
+                        .downcast_ref::<$ty>()
+                        .and_then(|typed| $crate::serde_json::to_string(typed).ok())
+                },
+                reconstruct: |payload: &str| match $crate::serde_json::from_str::<$ty>(payload) {
+                    ::core::result::Result::Ok(typed) => ::anyhow::Error::new(typed),
+                    ::core::result::Result::Err(_) => {
+                        ::anyhow::anyhow!($crate::DisplayString(payload.to_owned()))
+                    }
+                },
+            }
         }
-    }
-    deserializer.deserialize_str(SourceVisitor)
+    };
 }
 
+#[doc(hidden)]
+pub use inventory;
+#[doc(hidden)]
+pub use serde_json;
+
 #[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Location {
-    pub file: Cow<'static, str>,
+    #[serde(
+        serialize_with = "serialize_file",
+        deserialize_with = "deserialize_file"
+    )]
+    pub file: Arc<str>,
     pub line: u32,
     pub col: u32,
 }
 
+fn interner() -> &'static Mutex<HashSet<Arc<str>>> {
+    static INTERNER: OnceLock<Mutex<HashSet<Arc<str>>>> = OnceLock::new();
+    INTERNER.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+thread_local! {
+    static LAST_LOC_FILENAME: RefCell<Option<Arc<str>>> = const { RefCell::new(None) };
+    static LAST_DESERIALIZED_FILENAME: RefCell<Option<Arc<str>>> = const { RefCell::new(None) };
+}
+
+/// Intern a file path into the global set so that identical paths share a
+/// single allocation for the rest of the process. This is only sound for the
+/// small, fixed set of real `&'static` source paths reported by
+/// [`std::panic::Location`]; do **not** feed untrusted input here, since the
+/// backing set is never evicted. A thread-local cache of the last-seen filename
+/// (à la clang-ast's `LAST_LOC_FILENAME`) short-circuits the common case of the
+/// same path arriving in a run before we touch the global set.
+pub fn intern(s: &str) -> Arc<str> {
+    if let Some(cached) = last_loc_filename(s) {
+        return cached;
+    }
+    let arc = {
+        let mut set = interner().lock().unwrap();
+        if let Some(existing) = set.get(s) {
+            existing.clone()
+        } else {
+            let arc: Arc<str> = Arc::from(s);
+            set.insert(arc.clone());
+            arc
+        }
+    };
+    LAST_LOC_FILENAME.with(|last| *last.borrow_mut() = Some(arc.clone()));
+    arc
+}
+
+/// Intern a file path coming from deserialization. Unlike [`intern`], this
+/// never touches the unbounded global set — deserialized paths are
+/// attacker-controlled, so admitting them there would be a memory-exhaustion
+/// vector. Consecutive identical paths (the common shape of a `backtrace`)
+/// still collapse via a thread-local last-seen cache of their own, kept apart
+/// from the one [`intern`] reads so it never hands out a non-interned path.
+fn intern_transient(s: &str) -> Arc<str> {
+    if let Some(cached) = last_loc_filename(s) {
+        return cached;
+    }
+    let cached =
+        LAST_DESERIALIZED_FILENAME.with(|last| last.borrow().clone().filter(|f| **f == *s));
+    if let Some(cached) = cached {
+        return cached;
+    }
+    let arc: Arc<str> = Arc::from(s);
+    LAST_DESERIALIZED_FILENAME.with(|last| *last.borrow_mut() = Some(arc.clone()));
+    arc
+}
+
+fn last_loc_filename(s: &str) -> Option<Arc<str>> {
+    LAST_LOC_FILENAME.with(|last| last.borrow().clone().filter(|f| **f == *s))
+}
+
+fn serialize_file<S: Serializer>(file: &Arc<str>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(file)
+}
+
+fn deserialize_file<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Arc<str>, D::Error> {
+    let file = <Cow<'de, str>>::deserialize(deserializer)?;
+    Ok(intern_transient(&file))
+}
+
 impl From<&'static StdLocation<'static>> for Location {
     fn from(value: &'static StdLocation) -> Self {
         Self {
-            file: value.file().into(),
+            file: intern(value.file()),
             line: value.line(),
             col: value.column(),
         }
@@ -195,6 +408,821 @@ impl<T> ToLocation<T> for Option<T> {
     }
 }
 
+/// Deserialize `T` through a tracking adapter that remembers *where in the
+/// input* a failure happened, not just the Rust call site. On error the dotted
+/// data-path of the offending field (`config.servers[2].port`) is attached to
+/// the returned [`LocationError`] as `context`, alongside the original serde
+/// message and the caller [`Location`].
+///
+/// The path is threaded as a borrowed linked list of [`Chain`] nodes grown on
+/// the stack as deserialization descends and unwound as each frame returns, so
+/// the nodes themselves are never heap-allocated (à la `serde_path_to_error`).
+/// Each map key is still copied into an owned `String` so that the node for its
+/// value can name it.
+#[track_caller]
+pub fn deserialize_loc<'de, T, D>(deserializer: D) -> LocationResult<T>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let track = Track::new();
+    let root = Chain::Root;
+    let de = PathDeserializer::new(deserializer, &root, &track);
+    match T::deserialize(de) {
+        Ok(value) => Ok(value),
+        Err(err) => {
+            let located = LocationError::new(anyhow!("{err}"));
+            Err(match track.into_path() {
+                Some(path) if !path.is_empty() => located.context(path),
+                _ => located,
+            })
+        }
+    }
+}
+
+/// Convenience wrapper around [`deserialize_loc`] for JSON text: on failure the
+/// returned [`LocationError`] names the offending field's data-path.
+#[track_caller]
+pub fn from_str_loc<T>(s: &str) -> LocationResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut de = serde_json::Deserializer::from_str(s);
+    let value = deserialize_loc(&mut de)?;
+    // Reject trailing data just like `serde_json::from_str` does, rather than
+    // silently accepting input after the first complete value.
+    de.end().map_err(LocationError::new)?;
+    Ok(value)
+}
+
+/// Records the data-path of the first (innermost) failure seen during a
+/// [`deserialize_loc`] run. The innermost adapter triggers first, so the
+/// deepest path wins and outer frames leave it untouched.
+struct Track {
+    path: RefCell<Option<String>>,
+}
+
+impl Track {
+    fn new() -> Self {
+        Track {
+            path: RefCell::new(None),
+        }
+    }
+
+    fn trigger<E>(&self, chain: &Chain, err: E) -> E {
+        let mut slot = self.path.borrow_mut();
+        if slot.is_none() {
+            *slot = Some(chain.to_path());
+        }
+        err
+    }
+
+    fn into_path(self) -> Option<String> {
+        self.path.into_inner()
+    }
+}
+
+/// A borrowed linked list of path segments. Each descent pushes a node whose
+/// `parent` points at the enclosing frame's node; nodes live on the stack and
+/// disappear as recursion unwinds.
+enum Chain<'a> {
+    Root,
+    Seq { parent: &'a Chain<'a>, index: usize },
+    Map { parent: &'a Chain<'a>, key: &'a str },
+}
+
+impl Chain<'_> {
+    fn to_path(&self) -> String {
+        enum Seg<'s> {
+            Index(usize),
+            Key(&'s str),
+        }
+        let mut segments = Vec::new();
+        let mut node = self;
+        loop {
+            match node {
+                Chain::Root => break,
+                Chain::Seq { parent, index } => {
+                    segments.push(Seg::Index(*index));
+                    node = parent;
+                }
+                Chain::Map { parent, key } => {
+                    segments.push(Seg::Key(key));
+                    node = parent;
+                }
+            }
+        }
+        let mut out = String::new();
+        for seg in segments.into_iter().rev() {
+            match seg {
+                Seg::Key(key) => {
+                    if !out.is_empty() {
+                        out.push('.');
+                    }
+                    out.push_str(key);
+                }
+                Seg::Index(index) => {
+                    out.push_str(&format!("[{index}]"));
+                }
+            }
+        }
+        out
+    }
+}
+
+struct PathDeserializer<'a, D> {
+    inner: D,
+    chain: &'a Chain<'a>,
+    track: &'a Track,
+}
+
+impl<'a, D> PathDeserializer<'a, D> {
+    fn new(inner: D, chain: &'a Chain<'a>, track: &'a Track) -> Self {
+        PathDeserializer {
+            inner,
+            chain,
+            track,
+        }
+    }
+}
+
+macro_rules! path_forward {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, D::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let (chain, track) = (self.chain, self.track);
+                self.inner
+                    .$method(Wrap::new(visitor, chain, track))
+                    .map_err(|e| track.trigger(chain, e))
+            }
+        )*
+    };
+}
+
+impl<'a, 'de, D> Deserializer<'de> for PathDeserializer<'a, D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    path_forward! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_option deserialize_unit deserialize_seq deserialize_map
+        deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_unit_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.inner
+            .deserialize_unit_struct(name, Wrap::new(visitor, chain, track))
+            .map_err(|e| track.trigger(chain, e))
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.inner
+            .deserialize_newtype_struct(name, Wrap::new(visitor, chain, track))
+            .map_err(|e| track.trigger(chain, e))
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.inner
+            .deserialize_tuple(len, Wrap::new(visitor, chain, track))
+            .map_err(|e| track.trigger(chain, e))
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.inner
+            .deserialize_tuple_struct(name, len, Wrap::new(visitor, chain, track))
+            .map_err(|e| track.trigger(chain, e))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.inner
+            .deserialize_struct(name, fields, Wrap::new(visitor, chain, track))
+            .map_err(|e| track.trigger(chain, e))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.inner
+            .deserialize_enum(name, variants, Wrap::new(visitor, chain, track))
+            .map_err(|e| track.trigger(chain, e))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// Wraps the caller's [`Visitor`] so that compound values (`seq`/`map`/`enum`)
+/// descend through tracking accessors while scalars pass straight through.
+struct Wrap<'a, V> {
+    delegate: V,
+    chain: &'a Chain<'a>,
+    track: &'a Track,
+}
+
+impl<'a, V> Wrap<'a, V> {
+    fn new(delegate: V, chain: &'a Chain<'a>, track: &'a Track) -> Self {
+        Wrap {
+            delegate,
+            chain,
+            track,
+        }
+    }
+}
+
+macro_rules! wrap_visit {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.delegate.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'a, 'de, V> Visitor<'de> for Wrap<'a, V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.delegate.expecting(formatter)
+    }
+
+    wrap_visit! {
+        visit_bool(bool) visit_i8(i8) visit_i16(i16) visit_i32(i32) visit_i64(i64)
+        visit_i128(i128) visit_u8(u8) visit_u16(u16) visit_u32(u32) visit_u64(u64)
+        visit_u128(u128) visit_f32(f32) visit_f64(f64) visit_char(char)
+        visit_str(&str) visit_borrowed_str(&'de str) visit_string(String)
+        visit_bytes(&[u8]) visit_borrowed_bytes(&'de [u8]) visit_byte_buf(Vec<u8>)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.delegate.visit_none()
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.delegate.visit_unit()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.delegate
+            .visit_some(PathDeserializer::new(deserializer, self.chain, self.track))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.delegate
+            .visit_newtype_struct(PathDeserializer::new(deserializer, self.chain, self.track))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        self.delegate.visit_seq(SeqTracker {
+            delegate: seq,
+            chain: self.chain,
+            track: self.track,
+            index: 0,
+        })
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        self.delegate.visit_map(MapTracker {
+            delegate: map,
+            chain: self.chain,
+            track: self.track,
+            key: None,
+        })
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::EnumAccess<'de>,
+    {
+        self.delegate.visit_enum(EnumTracker {
+            delegate: data,
+            chain: self.chain,
+            track: self.track,
+        })
+    }
+}
+
+/// Re-wraps a nested deserializer with the child [`Chain`] node before handing
+/// it to the original seed.
+struct Seed<'a, X> {
+    seed: X,
+    chain: &'a Chain<'a>,
+    track: &'a Track,
+}
+
+impl<'a, 'de, X> de::DeserializeSeed<'de> for Seed<'a, X>
+where
+    X: de::DeserializeSeed<'de>,
+{
+    type Value = X::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.seed
+            .deserialize(PathDeserializer::new(deserializer, self.chain, self.track))
+    }
+}
+
+struct SeqTracker<'a, X> {
+    delegate: X,
+    chain: &'a Chain<'a>,
+    track: &'a Track,
+    index: usize,
+}
+
+impl<'a, 'de, X> de::SeqAccess<'de> for SeqTracker<'a, X>
+where
+    X: de::SeqAccess<'de>,
+{
+    type Error = X::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, X::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        let index = self.index;
+        self.index += 1;
+        let chain = Chain::Seq {
+            parent: self.chain,
+            index,
+        };
+        let track = self.track;
+        self.delegate
+            .next_element_seed(Seed {
+                seed,
+                chain: &chain,
+                track,
+            })
+            .map_err(|e| track.trigger(&chain, e))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.delegate.size_hint()
+    }
+}
+
+struct MapTracker<'a, X> {
+    delegate: X,
+    chain: &'a Chain<'a>,
+    track: &'a Track,
+    key: Option<String>,
+}
+
+impl<'a, 'de, X> de::MapAccess<'de> for MapTracker<'a, X>
+where
+    X: de::MapAccess<'de>,
+{
+    type Error = X::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, X::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.delegate
+            .next_key_seed(CaptureKey {
+                seed,
+                key: &mut self.key,
+            })
+            .map_err(|e| track.trigger(chain, e))
+    }
+
+    fn next_value_seed<Va>(&mut self, seed: Va) -> Result<Va::Value, X::Error>
+    where
+        Va: de::DeserializeSeed<'de>,
+    {
+        let key = self.key.take().unwrap_or_default();
+        let chain = Chain::Map {
+            parent: self.chain,
+            key: &key,
+        };
+        let track = self.track;
+        self.delegate
+            .next_value_seed(Seed {
+                seed,
+                chain: &chain,
+                track,
+            })
+            .map_err(|e| track.trigger(&chain, e))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.delegate.size_hint()
+    }
+}
+
+struct EnumTracker<'a, X> {
+    delegate: X,
+    chain: &'a Chain<'a>,
+    track: &'a Track,
+}
+
+impl<'a, 'de, X> de::EnumAccess<'de> for EnumTracker<'a, X>
+where
+    X: de::EnumAccess<'de>,
+{
+    type Error = X::Error;
+    type Variant = VariantTracker<'a, X::Variant>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), X::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.delegate
+            .variant_seed(Seed { seed, chain, track })
+            .map(|(value, variant)| {
+                (
+                    value,
+                    VariantTracker {
+                        delegate: variant,
+                        chain,
+                        track,
+                    },
+                )
+            })
+            .map_err(|e| track.trigger(chain, e))
+    }
+}
+
+struct VariantTracker<'a, X> {
+    delegate: X,
+    chain: &'a Chain<'a>,
+    track: &'a Track,
+}
+
+impl<'a, 'de, X> de::VariantAccess<'de> for VariantTracker<'a, X>
+where
+    X: de::VariantAccess<'de>,
+{
+    type Error = X::Error;
+
+    fn unit_variant(self) -> Result<(), X::Error> {
+        self.delegate
+            .unit_variant()
+            .map_err(|e| self.track.trigger(self.chain, e))
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, X::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.delegate
+            .newtype_variant_seed(Seed { seed, chain, track })
+            .map_err(|e| track.trigger(chain, e))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, X::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.delegate
+            .tuple_variant(len, Wrap::new(visitor, chain, track))
+            .map_err(|e| track.trigger(chain, e))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, X::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (chain, track) = (self.chain, self.track);
+        self.delegate
+            .struct_variant(fields, Wrap::new(visitor, chain, track))
+            .map_err(|e| track.trigger(chain, e))
+    }
+}
+
+/// Deserializes a map key with the caller's seed while snapshotting its scalar
+/// value into `key`, so the following value's [`Chain`] node can name it.
+struct CaptureKey<'k, X> {
+    seed: X,
+    key: &'k mut Option<String>,
+}
+
+impl<'k, 'de, X> de::DeserializeSeed<'de> for CaptureKey<'k, X>
+where
+    X: de::DeserializeSeed<'de>,
+{
+    type Value = X::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.seed.deserialize(CaptureKeyDeserializer {
+            inner: deserializer,
+            key: self.key,
+        })
+    }
+}
+
+struct CaptureKeyDeserializer<'k, D> {
+    inner: D,
+    key: &'k mut Option<String>,
+}
+
+macro_rules! capture_forward {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, D::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.inner.$method(CaptureKeyVisitor {
+                    delegate: visitor,
+                    key: self.key,
+                })
+            }
+        )*
+    };
+}
+
+impl<'k, 'de, D> Deserializer<'de> for CaptureKeyDeserializer<'k, D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    capture_forward! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_option deserialize_unit deserialize_seq deserialize_map
+        deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_unit_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.deserialize_unit_struct(
+            name,
+            CaptureKeyVisitor {
+                delegate: visitor,
+                key: self.key,
+            },
+        )
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.deserialize_newtype_struct(
+            name,
+            CaptureKeyVisitor {
+                delegate: visitor,
+                key: self.key,
+            },
+        )
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.deserialize_tuple(
+            len,
+            CaptureKeyVisitor {
+                delegate: visitor,
+                key: self.key,
+            },
+        )
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.deserialize_tuple_struct(
+            name,
+            len,
+            CaptureKeyVisitor {
+                delegate: visitor,
+                key: self.key,
+            },
+        )
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.deserialize_struct(
+            name,
+            fields,
+            CaptureKeyVisitor {
+                delegate: visitor,
+                key: self.key,
+            },
+        )
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.deserialize_enum(
+            name,
+            variants,
+            CaptureKeyVisitor {
+                delegate: visitor,
+                key: self.key,
+            },
+        )
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// Forwards to the caller's key visitor while recording the scalar key value as
+/// a string. serde's default `visit_*` forwards (`visit_string`→`visit_str`,
+/// `visit_i8`→`visit_i64`, …) funnel the common key shapes through the handful
+/// we override here.
+struct CaptureKeyVisitor<'k, V> {
+    delegate: V,
+    key: &'k mut Option<String>,
+}
+
+impl<'k, 'de, V> Visitor<'de> for CaptureKeyVisitor<'k, V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.delegate.expecting(formatter)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        *self.key = Some(v.to_owned());
+        self.delegate.visit_str(v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        *self.key = Some(String::from_utf8_lossy(v).into_owned());
+        self.delegate.visit_bytes(v)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        *self.key = Some(v.to_string());
+        self.delegate.visit_i64(v)
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        *self.key = Some(v.to_string());
+        self.delegate.visit_i128(v)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        *self.key = Some(v.to_string());
+        self.delegate.visit_u64(v)
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        *self.key = Some(v.to_string());
+        self.delegate.visit_u128(v)
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        *self.key = Some(v.to_string());
+        self.delegate.visit_bool(v)
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        *self.key = Some(v.to_string());
+        self.delegate.visit_char(v)
+    }
+}
+
 #[test]
 fn location_error_serde() {
     let err = Err::<(), _>(anyhow!("Some message")).loc().unwrap_err();
@@ -206,6 +1234,108 @@ fn location_error_serde() {
     dbg!(recovered_err);
 }
 
+#[cfg(test)]
+#[derive(Debug, Serialize, Deserialize)]
+struct RegisteredError {
+    code: u32,
+    message: String,
+}
+
+#[cfg(test)]
+impl std::fmt::Display for RegisteredError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error {}: {}", self.code, self.message)
+    }
+}
+
+#[cfg(test)]
+impl std::error::Error for RegisteredError {}
+
+#[cfg(test)]
+register_ser_loc_error!(RegisteredError, "registered_error_test");
+
+#[test]
+fn registered_error_survives_round_trip() {
+    let err = Err::<(), _>(RegisteredError {
+        code: 7,
+        message: "boom".into(),
+    })
+    .loc()
+    .context("doing thing")
+    .context("outer")
+    .unwrap_err();
+
+    let json = serde_json::to_string(&err).unwrap();
+    let recovered = serde_json::from_str::<LocationError>(&json).unwrap();
+
+    // The concrete type survives, so a receiver can still branch on it.
+    let typed = recovered
+        .source
+        .downcast_ref::<RegisteredError>()
+        .expect("concrete type should survive transport");
+    assert_eq!(typed.code, 7);
+    assert_eq!(typed.message, "boom");
+
+    // ...and the `.context(...)` frames are not dropped on the tagged path.
+    assert_eq!(recovered.source.chain().count(), err.source.chain().count());
+    assert_eq!(format!("{}", recovered.source), "outer");
+    assert_eq!(format!("{:#}", recovered.source), format!("{:#}", err.source));
+}
+
+#[test]
+fn from_str_loc_rejects_trailing_data() {
+    assert!(from_str_loc::<u32>("42 garbage").is_err());
+    assert_eq!(from_str_loc::<u32>("42").unwrap(), 42);
+}
+
+#[test]
+fn source_chain_survives_round_trip() {
+    let err = Err::<(), _>(anyhow!("root cause"))
+        .loc()
+        .context("while loading config")
+        .context("startup failed")
+        .unwrap_err();
+
+    assert_eq!(err.source.chain().count(), 3);
+
+    let json = serde_json::to_string(&err).unwrap();
+    let recovered = serde_json::from_str::<LocationError>(&json).unwrap();
+
+    assert_eq!(recovered.source.chain().count(), 3);
+    assert_eq!(format!("{}", recovered.source), "startup failed");
+    assert_eq!(format!("{:#}", recovered.source), format!("{:#}", err.source));
+}
+
+#[test]
+fn deserialize_loc_reports_field_path() {
+    #[derive(Deserialize)]
+    struct Server {
+        #[allow(dead_code)]
+        port: u16,
+    }
+    #[derive(Deserialize)]
+    struct Config {
+        #[allow(dead_code)]
+        servers: Vec<Server>,
+    }
+    #[derive(Deserialize)]
+    struct Root {
+        #[allow(dead_code)]
+        config: Config,
+    }
+
+    let json = r#"{"config":{"servers":[{"port":8080},{"port":"nope"}]}}"#;
+    let err = from_str_loc::<Root>(json).err().expect("expected a parse error");
+
+    assert!(
+        err.source
+            .chain()
+            .any(|cause| cause.to_string() == "config.servers[1].port"),
+        "missing data-path context: {:#?}",
+        err.source,
+    );
+}
+
 #[derive(Serialize, Deserialize, Default, Clone)]
 pub struct DisplayString(pub String);
 